@@ -57,18 +57,19 @@ pub trait Capitalize {
 
 impl Capitalize for String {
     fn capitalize(self) -> String {
-        return if self.len() > 0 {
-            self[..1].to_uppercase() + &self[1..]
-        } else { String::new() };
+        return self.as_str().capitalize();
     }
 }
 
 
 impl Capitalize for &str {
     fn capitalize(self) -> String {
-        return if self.len() > 0 {
-            self[..1].to_uppercase() + &self[1..]
-        } else { String::new() };
+        let mut chars = self.chars();
+
+        return match chars.next() {
+            Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => String::new(),
+        };
     }
 }
 
@@ -106,25 +107,152 @@ pub trait CapitalizeUntrimmed {
 
 impl CapitalizeUntrimmed for String {
     fn capitalize_untrimmed(self) -> String {
-        let idx = self.find(|c: char| !c.is_whitespace())
-            .map(|i| i + 1)
-            .unwrap_or(1);
-
-        return if self.len() > 0 {
-            self[..idx].to_uppercase() + &self[idx..]
-        } else { String::new() };
+        return self.as_str().capitalize_untrimmed();
     }
 }
 
 
 impl CapitalizeUntrimmed for &str {
     fn capitalize_untrimmed(self) -> String {
-        let idx = self.find(|c: char| !c.is_whitespace())
-            .map(|i| i + 1)
-            .unwrap_or(1);
+        let idx = match self.find(|c: char| !c.is_whitespace()) {
+            Some(idx) => idx,
+            None => return self.to_string(),
+        };
+
+        let (whitespace, rest) = self.split_at(idx);
+        let mut chars = rest.chars();
+
+        return match chars.next() {
+            Some(first) => whitespace.to_string() + &first.to_uppercase().collect::<String>() + chars.as_str(),
+            None => self.to_string(),
+        };
+    }
+}
+
+
+/// Trait implemented on [`String`]s & [`str`]s to capitalize the first character of every whitespace-separated word.
+/// 
+/// # Example
+/// 
+/// ```rust
+/// use phesm::traits::capitalize::CapitalizeWords;
+/// 
+/// let string = "hello world".capitalize_words();
+/// assert_eq!(string, "Hello World".to_string());
+/// ```
+pub trait CapitalizeWords {
+    /// Takes a [`String`] or [`str`] and capitalizes the first character of every whitespace-separated word,
+    /// preserving the original whitespace separators between words.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use phesm::traits::capitalize::CapitalizeWords;
+    /// 
+    /// let string = "hello world".capitalize_words();
+    /// assert_eq!(string, "Hello World".to_string());
+    /// ```
+    fn capitalize_words(self) -> String;
+}
 
-        return if self.len() > 0 {
-            self[..idx].to_uppercase() + &self[idx..]
-        } else { String::new() };
+
+impl CapitalizeWords for String {
+    fn capitalize_words(self) -> String {
+        return self.as_str().capitalize_words();
     }
-}
\ No newline at end of file
+}
+
+
+impl CapitalizeWords for &str {
+    fn capitalize_words(self) -> String {
+        return self.split_inclusive(char::is_whitespace)
+            .map(|word| word.capitalize())
+            .collect::<String>();
+    }
+}
+
+
+/// The default set of small words left lowercase by [`TitleCase::title_case`], unless they're the first or last word of the title.
+pub const DEFAULT_TITLE_CASE_EXCEPTIONS: &[&str] = &[
+    "a", "an", "the",
+    "and", "or", "but", "nor",
+    "of", "to", "in", "on", "with", "vs",
+];
+
+
+/// Trait implemented on [`String`]s & [`str`]s to apply English title-casing rules.
+///
+/// Every word is capitalized except for a set of small words (articles, conjunctions, short prepositions),
+/// though the first and last words of the title are always capitalized regardless of whether they're in the exception set.
+///
+/// # Example
+///
+/// ```rust
+/// use phesm::traits::capitalize::TitleCase;
+///
+/// let string = "the lord of the rings".title_case();
+/// assert_eq!(string, "The Lord of the Rings".to_string());
+/// ```
+pub trait TitleCase {
+    /// Title-cases `self` using [`DEFAULT_TITLE_CASE_EXCEPTIONS`] as the set of small words to leave lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use phesm::traits::capitalize::TitleCase;
+    ///
+    /// let string = "the lord of the rings".title_case();
+    /// assert_eq!(string, "The Lord of the Rings".to_string());
+    /// ```
+    fn title_case(self) -> String;
+
+    /// Title-cases `self` using a custom set of small words to leave lowercase.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::collections::HashSet;
+    /// use phesm::traits::capitalize::TitleCase;
+    ///
+    /// let exceptions = HashSet::from(["of"]);
+    /// let string = "the lord of the rings".title_case_with(&exceptions);
+    /// assert_eq!(string, "The Lord of The Rings".to_string());
+    /// ```
+    fn title_case_with(self, exceptions: &std::collections::HashSet<&str>) -> String;
+}
+
+
+impl TitleCase for String {
+    fn title_case(self) -> String {
+        return self.as_str().title_case();
+    }
+
+    fn title_case_with(self, exceptions: &std::collections::HashSet<&str>) -> String {
+        return self.as_str().title_case_with(exceptions);
+    }
+}
+
+
+impl TitleCase for &str {
+    fn title_case(self) -> String {
+        let exceptions = std::collections::HashSet::from_iter(DEFAULT_TITLE_CASE_EXCEPTIONS.iter().copied());
+        return self.title_case_with(&exceptions);
+    }
+
+    fn title_case_with(self, exceptions: &std::collections::HashSet<&str>) -> String {
+        let words = self.split_whitespace().collect::<Vec<_>>();
+        let last_idx = words.len().saturating_sub(1);
+
+        return words.iter()
+            .enumerate()
+            .map(|(idx, word)| {
+                if idx == 0 || idx == last_idx || !exceptions.contains(word.to_lowercase().as_str()) {
+                    word.capitalize()
+                } else {
+                    word.to_lowercase()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+}
@@ -16,8 +16,12 @@
 //! assert_eq!(string_data.string, "Hello, World!".to_string());
 //! ```
 
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
 use serde::{de, Deserialize};
 
+use crate::traits::capitalize::Capitalize;
+
 
 /// Helper function to be used with Serde's `deserialize_with` attribute to trim a [`String`] field.
 /// 
@@ -80,4 +84,423 @@ where
     }
 
     return Ok(option);
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`Vec`] field.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::vec_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "vec_string_trim")]
+///     pub strings: Vec<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!", "  Foo  "] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, vec!["Hello, World!".to_string(), "Foo".to_string()]);
+/// ```
+pub fn vec_string_trim<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = Vec::<String>::deserialize(deserializer)?;
+    return Ok(strings.into_iter().map(|string| string.trim().to_string()).collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`Vec`] field,
+/// dropping any element that becomes empty after trimming.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::vec_non_empty_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "vec_non_empty_string_trim")]
+///     pub strings: Vec<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!", "   "] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, vec!["Hello, World!".to_string()]);
+/// ```
+pub fn vec_non_empty_string_trim<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = Vec::<String>::deserialize(deserializer)?;
+
+    return Ok(strings.into_iter()
+        .map(|string| string.trim().to_string())
+        .filter(|string| !string.is_empty())
+        .collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`HashSet`] field.
+/// 
+/// # Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use phesm::util::serde::hashset_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "hashset_string_trim")]
+///     pub strings: HashSet<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!"] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, HashSet::from(["Hello, World!".to_string()]));
+/// ```
+pub fn hashset_string_trim<'de, D>(deserializer: D) -> Result<HashSet<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = HashSet::<String>::deserialize(deserializer)?;
+    return Ok(strings.into_iter().map(|string| string.trim().to_string()).collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`HashSet`] field,
+/// dropping any element that becomes empty after trimming.
+/// 
+/// # Example
+/// ```rust
+/// use std::collections::HashSet;
+/// use phesm::util::serde::hashset_non_empty_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "hashset_non_empty_string_trim")]
+///     pub strings: HashSet<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!", "   "] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, HashSet::from(["Hello, World!".to_string()]));
+/// ```
+pub fn hashset_non_empty_string_trim<'de, D>(deserializer: D) -> Result<HashSet<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = HashSet::<String>::deserialize(deserializer)?;
+
+    return Ok(strings.into_iter()
+        .map(|string| string.trim().to_string())
+        .filter(|string| !string.is_empty())
+        .collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`BTreeSet`] field.
+/// 
+/// # Example
+/// ```rust
+/// use std::collections::BTreeSet;
+/// use phesm::util::serde::btreeset_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "btreeset_string_trim")]
+///     pub strings: BTreeSet<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!"] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, BTreeSet::from(["Hello, World!".to_string()]));
+/// ```
+pub fn btreeset_string_trim<'de, D>(deserializer: D) -> Result<BTreeSet<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = BTreeSet::<String>::deserialize(deserializer)?;
+    return Ok(strings.into_iter().map(|string| string.trim().to_string()).collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`BTreeSet`] field,
+/// dropping any element that becomes empty after trimming.
+/// 
+/// # Example
+/// ```rust
+/// use std::collections::BTreeSet;
+/// use phesm::util::serde::btreeset_non_empty_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "btreeset_non_empty_string_trim")]
+///     pub strings: BTreeSet<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!", "   "] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, BTreeSet::from(["Hello, World!".to_string()]));
+/// ```
+pub fn btreeset_non_empty_string_trim<'de, D>(deserializer: D) -> Result<BTreeSet<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = BTreeSet::<String>::deserialize(deserializer)?;
+
+    return Ok(strings.into_iter()
+        .map(|string| string.trim().to_string())
+        .filter(|string| !string.is_empty())
+        .collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`VecDeque`] field.
+/// 
+/// # Example
+/// ```rust
+/// use std::collections::VecDeque;
+/// use phesm::util::serde::vecdeque_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "vecdeque_string_trim")]
+///     pub strings: VecDeque<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!"] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, VecDeque::from(["Hello, World!".to_string()]));
+/// ```
+pub fn vecdeque_string_trim<'de, D>(deserializer: D) -> Result<VecDeque<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = VecDeque::<String>::deserialize(deserializer)?;
+    return Ok(strings.into_iter().map(|string| string.trim().to_string()).collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim every [`String`] element of a [`VecDeque`] field,
+/// dropping any element that becomes empty after trimming.
+/// 
+/// # Example
+/// ```rust
+/// use std::collections::VecDeque;
+/// use phesm::util::serde::vecdeque_non_empty_string_trim;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "vecdeque_non_empty_string_trim")]
+///     pub strings: VecDeque<String>,
+/// }
+/// 
+/// let json = r#"{ "strings": ["    Hello, World!", "   "] }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.strings, VecDeque::from(["Hello, World!".to_string()]));
+/// ```
+pub fn vecdeque_non_empty_string_trim<'de, D>(deserializer: D) -> Result<VecDeque<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let strings = VecDeque::<String>::deserialize(deserializer)?;
+
+    return Ok(strings.into_iter()
+        .map(|string| string.trim().to_string())
+        .filter(|string| !string.is_empty())
+        .collect());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to lowercase a [`String`] field.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::lowercase_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "lowercase_string")]
+///     pub string: String,
+/// }
+/// 
+/// let json = r#"{ "string": "Hello, World!" }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.string, "hello, world!".to_string());
+/// ```
+pub fn lowercase_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    return Ok(string.to_lowercase());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to lowercase a [`String`] field wrapped in an [`Option`] enum.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::lowercase_optional_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "lowercase_optional_string")]
+///     pub maybe_string: Option<String>,
+/// }
+/// 
+/// let json = r#"{ "maybe_string": "Hello, World!" }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.maybe_string.unwrap(), "hello, world!".to_string());
+/// ```
+pub fn lowercase_optional_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let option = Option::<String>::deserialize(deserializer)?;
+    return Ok(option.map(|string| string.to_lowercase()));
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to uppercase a [`String`] field.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::uppercase_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "uppercase_string")]
+///     pub string: String,
+/// }
+/// 
+/// let json = r#"{ "string": "Hello, World!" }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.string, "HELLO, WORLD!".to_string());
+/// ```
+pub fn uppercase_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    return Ok(string.to_uppercase());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to uppercase a [`String`] field wrapped in an [`Option`] enum.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::uppercase_optional_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "uppercase_optional_string")]
+///     pub maybe_string: Option<String>,
+/// }
+/// 
+/// let json = r#"{ "maybe_string": "Hello, World!" }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.maybe_string.unwrap(), "HELLO, WORLD!".to_string());
+/// ```
+pub fn uppercase_optional_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let option = Option::<String>::deserialize(deserializer)?;
+    return Ok(option.map(|string| string.to_uppercase()));
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to capitalize the first character of a [`String`] field.
+/// Reuses the UTF-8-safe [`Capitalize`] implementation, so multibyte first characters are handled correctly.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::capitalize_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "capitalize_string")]
+///     pub string: String,
+/// }
+/// 
+/// let json = r#"{ "string": "hello, world!" }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.string, "Hello, world!".to_string());
+/// ```
+pub fn capitalize_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    return Ok(string.capitalize());
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to capitalize the first character of a [`String`] field
+/// wrapped in an [`Option`] enum. Reuses the UTF-8-safe [`Capitalize`] implementation, so multibyte first characters are handled correctly.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::capitalize_optional_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "capitalize_optional_string")]
+///     pub maybe_string: Option<String>,
+/// }
+/// 
+/// let json = r#"{ "maybe_string": "hello, world!" }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.maybe_string.unwrap(), "Hello, world!".to_string());
+/// ```
+pub fn capitalize_optional_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let option = Option::<String>::deserialize(deserializer)?;
+    return Ok(option.map(|string| string.capitalize()));
+}
+
+
+/// Helper function to be used with Serde's `deserialize_with` attribute to trim a [`String`] field and then capitalize
+/// its first character. Composes [`trim_string`] and [`capitalize_string`], which is frequently wanted together.
+/// 
+/// # Example
+/// ```rust
+/// use phesm::util::serde::trim_and_capitalize_string;
+/// use serde_json::from_str;
+/// 
+/// #[derive(Debug, serde::Deserialize)]
+/// pub struct StringData {
+///     #[serde(deserialize_with = "trim_and_capitalize_string")]
+///     pub string: String,
+/// }
+/// 
+/// let json = r#"{ "string": "   hello, world!  " }"#;
+/// let string_data = from_str::<StringData>(json).unwrap();
+/// assert_eq!(string_data.string, "Hello, world!".to_string());
+/// ```
+pub fn trim_and_capitalize_string<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+    return Ok(string.trim().capitalize());
 }
\ No newline at end of file